@@ -0,0 +1,64 @@
+//! Reads `instructions.in`, the declarative instruction table, and emits
+//! `$OUT_DIR/rel_ops.rs`: the `REL_OPS` array enumerating every `RelOp`
+//! variant in the table's row order. `op_rel.rs` `include!`s the result
+//! instead of hand-listing the variants a second time, so the array and
+//! `instructions.in` can't drift apart the way a second hand-maintained copy
+//! could.
+//!
+//! Generating the full `EventTableOpcodeConfigBuilder` boilerplate and
+//! `specs::itable` encoding this table describes would require codegen into
+//! the `specs` crate, which this tree doesn't vendor, so only the `Rel`
+//! opcode's own variant ordering is generated for now.
+
+use std::{env, fmt::Write as _, fs, path::Path};
+
+struct InstructionRow {
+    class: String,
+    variant: String,
+}
+
+fn parse_instructions(spec: &str) -> Vec<InstructionRow> {
+    spec.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 5 {
+                panic!("malformed instructions.in row: {:?}", fields);
+            }
+            InstructionRow {
+                class: fields[0].to_string(),
+                variant: fields[1].to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Emits `const REL_OPS: [RelOp; N] = [RelOp::Variant, ...];` for every `Rel`
+/// row, in table order.
+fn emit_rel_ops(rows: &[InstructionRow]) -> String {
+    let mut out = String::new();
+    writeln!(out, "// @generated by build.rs from instructions.in. Do not edit by hand.").unwrap();
+    write!(out, "const REL_OPS: [RelOp; {}] = [", rows.len()).unwrap();
+    for row in rows {
+        write!(out, "RelOp::{}, ", row.variant).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    out
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=instructions.in");
+
+    let spec = fs::read_to_string("instructions.in").expect("failed to read instructions.in");
+    let rel_rows: Vec<_> = parse_instructions(&spec)
+        .into_iter()
+        .filter(|row| row.class == "Rel")
+        .collect();
+
+    let generated = emit_rel_ops(&rel_rows);
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    let dest = Path::new(&out_dir).join("rel_ops.rs");
+    fs::write(dest, generated).expect("failed to write generated rel_ops.rs");
+}
@@ -5,7 +5,7 @@ use crate::{
         jtable::JumpTableConfig,
         mtable::MemoryTableConfig,
         rtable::RangeTableConfig,
-        utils::{bn_to_field, tvalue::TValueConfig, Context},
+        utils::{bn_to_field, binary_number::{AsBits, BinaryNumberBits}, tvalue::TValueConfig, Context},
     },
     constant, constant_from, curr,
 };
@@ -21,13 +21,114 @@ use specs::{
 };
 use std::vec;
 
+/// Bit width of a single limb. Operands are decomposed into a low and a high
+/// limb of this width (the high limb is always zero for `I32` operands), so
+/// the same comparison gadget proves both `I32` and `I64` relational ops.
+const LIMB_WIDTH: u32 = 32;
+
+// All `RelOp` variants, used to enumerate the `BinaryNumberBits` selector.
+// Generated by `build.rs` from `instructions.in`'s `Rel` rows, so the two
+// can't drift the way a hand-maintained second copy of this list could.
+// The order of the list does not matter: `AsBits for RelOp` below derives
+// each bit pattern from the variant's own `as u64`, and `REL_OPS` is only
+// summed over.
+include!(concat!(env!("OUT_DIR"), "/rel_ops.rs"));
+
+impl AsBits<4> for RelOp {
+    fn as_bits(&self) -> [bool; 4] {
+        let discriminant = *self as u64;
+        [0, 1, 2, 3].map(|i| (discriminant >> i) & 1 == 1)
+    }
+}
+
 pub struct RelOpConfig<F: FieldExt> {
     left: TValueConfig<F>,
     right: TValueConfig<F>,
+    left_hi: Column<Advice>,
+    right_hi: Column<Advice>,
     res: Column<Advice>,
     enable: Column<Advice>,
-    is_eq: Column<Advice>,
-    is_ne: Column<Advice>,
+    rel_op: BinaryNumberBits<F, 4>,
+    // Shared unsigned-comparison gadget, one instance per limb:
+    // `cmp_left + borrow * 2^LIMB_WIDTH = cmp_right + diff`, with `diff`
+    // range-checked via `rtable`. `borrow == 1` iff `cmp_left < cmp_right`.
+    // The low limb's borrow only decides the result when the high limbs are
+    // equal, which `hi_eq` (an is-zero gadget over `diff_hi`) witnesses.
+    diff: Column<Advice>,
+    borrow: Column<Advice>,
+    diff_hi: Column<Advice>,
+    borrow_hi: Column<Advice>,
+    hi_eq: Column<Advice>,
+    hi_diff_inv: Column<Advice>,
+    // Is-zero gadget over `diff` (the low limb), used to constrain `res` for
+    // `Eq`/`Ne` as `lo_eq AND hi_eq`.
+    lo_eq: Column<Advice>,
+    lo_diff_inv: Column<Advice>,
+    // Signed comparisons map onto the unsigned gadget by biasing the sign
+    // limb by 2^(LIMB_WIDTH-1) mod 2^LIMB_WIDTH (flipping the sign bit).
+    // `cmp_left_lo`/`cmp_right_lo` (resp. `_hi`) witness that reduced value,
+    // and `carry_left_lo`/`carry_right_lo` (resp. `_hi`) witness the mod
+    // carry, i.e. whether the pre-bias limb's top bit was set.
+    cmp_left_lo: Column<Advice>,
+    cmp_right_lo: Column<Advice>,
+    carry_left_lo: Column<Advice>,
+    carry_right_lo: Column<Advice>,
+    cmp_left_hi: Column<Advice>,
+    cmp_right_hi: Column<Advice>,
+    carry_left_hi: Column<Advice>,
+    carry_right_hi: Column<Advice>,
+    // u16/u16 decomposition backing each 32-bit range check (`diff`,
+    // `diff_hi`, `left_hi`, `right_hi`, and the four `cmp_*` columns above),
+    // routed through the shared `u16` subtable instead of one wide range
+    // argument.
+    range_check_limbs: [(Column<Advice>, Column<Advice>); 8],
+}
+
+impl<F: FieldExt> RelOpConfig<F> {
+    fn is_variant(&self, op: RelOp, meta: &mut VirtualCells<'_, F>) -> Expression<F> {
+        self.rel_op.value_equals(&op, meta)
+    }
+
+    fn is_any(&self, ops: &[RelOp], meta: &mut VirtualCells<'_, F>) -> Expression<F> {
+        ops.iter()
+            .fold(constant_from!(0), |acc, op| acc + self.is_variant(*op, meta))
+    }
+
+    fn is_i64(&self, meta: &mut VirtualCells<'_, F>) -> Expression<F> {
+        curr!(meta, self.left.vtype) - constant_from!(VarType::I32)
+    }
+}
+
+/// Range-check a 32-bit advice column by decomposing it into a low and a
+/// high 16-bit limb, each looked up in the shared `u16` subtable, rather than
+/// a single wide range argument. Returns the limb columns so `assign` can
+/// populate them.
+fn configure_u32_range_check<F: FieldExt>(
+    meta: &mut ConstraintSystem<F>,
+    rtable: &RangeTableConfig<F>,
+    cols: &mut impl Iterator<Item = Column<Advice>>,
+    name: &'static str,
+    value: Column<Advice>,
+    enable: impl Fn(&mut VirtualCells<'_, F>) -> Expression<F> + Clone + 'static,
+) -> (Column<Advice>, Column<Advice>) {
+    let lo16 = cols.next().unwrap();
+    let hi16 = cols.next().unwrap();
+
+    let enable_lo = enable.clone();
+    rtable.configure_u16_in_table(meta, name, move |meta| curr!(meta, lo16) * enable_lo(meta));
+    let enable_hi = enable.clone();
+    rtable.configure_u16_in_table(meta, name, move |meta| curr!(meta, hi16) * enable_hi(meta));
+
+    meta.create_gate(name, move |meta| {
+        vec![
+            (curr!(meta, value)
+                - curr!(meta, lo16)
+                - curr!(meta, hi16) * constant_from!(1u64 << 16))
+                * enable(meta),
+        ]
+    });
+
+    (lo16, hi16)
 }
 
 pub struct RelOpConfigBuilder {}
@@ -44,8 +145,27 @@ impl<F: FieldExt> EventTableOpcodeConfigBuilder<F> for RelOpConfigBuilder {
         _jtable: &JumpTableConfig<F>,
         enable: impl Fn(&mut VirtualCells<'_, F>) -> Expression<F>,
     ) -> Box<dyn EventTableOpcodeConfig<F>> {
-        let is_eq = cols.next().unwrap();
-        let is_ne = cols.next().unwrap();
+        let rel_op = BinaryNumberBits::configure(meta, cols, |meta| {
+            curr!(meta, opcode_bit) * enable(meta)
+        });
+        let diff = cols.next().unwrap();
+        let borrow = cols.next().unwrap();
+        let diff_hi = cols.next().unwrap();
+        let borrow_hi = cols.next().unwrap();
+        let hi_eq = cols.next().unwrap();
+        let hi_diff_inv = cols.next().unwrap();
+        let lo_eq = cols.next().unwrap();
+        let lo_diff_inv = cols.next().unwrap();
+        let left_hi = cols.next().unwrap();
+        let right_hi = cols.next().unwrap();
+        let cmp_left_lo = cols.next().unwrap();
+        let cmp_right_lo = cols.next().unwrap();
+        let carry_left_lo = cols.next().unwrap();
+        let carry_right_lo = cols.next().unwrap();
+        let cmp_left_hi = cols.next().unwrap();
+        let cmp_right_hi = cols.next().unwrap();
+        let carry_left_hi = cols.next().unwrap();
+        let carry_right_hi = cols.next().unwrap();
         let res = cols.next().unwrap();
         let left = TValueConfig::configure(meta, cols, rtable, |meta| {
             curr!(meta, opcode_bit) * enable(meta)
@@ -54,16 +174,266 @@ impl<F: FieldExt> EventTableOpcodeConfigBuilder<F> for RelOpConfigBuilder {
             curr!(meta, opcode_bit) * enable(meta)
         });
 
-        meta.create_gate("is eq or ne", |meta| {
+        meta.create_gate("res is bool", |meta| {
+            vec![curr!(meta, res) * (curr!(meta, res) - constant_from!(1)) * enable(meta)]
+        });
+
+        // `BinaryNumberBits` only constrains each bit to be boolean, so the
+        // 4-bit selector could otherwise encode 10..15, which is not a valid
+        // `RelOp`. Pin it down to exactly one of the 10 variants, the same
+        // way the old `is_eq + is_ne == 1` gate did before the chip existed.
+        meta.create_gate("rel op is a valid RelOp", |meta| {
+            let is_valid = REL_OPS
+                .iter()
+                .fold(constant_from!(0), |acc, op| acc + rel_op.value_equals(op, meta));
+            vec![(is_valid - constant_from!(1)) * enable(meta)]
+        });
+
+        // Range-check `diff`/`diff_hi`/`left_hi`/`right_hi` and the four
+        // `cmp_*` sign-bias reductions to `LIMB_WIDTH` bits so the borrow
+        // gadgets below are sound. Each is decomposed into two u16 lookups
+        // instead of one wide range argument.
+        let range_check_limbs = [
+            configure_u32_range_check(meta, rtable, cols, "rel diff range check", diff, |meta| {
+                curr!(meta, opcode_bit) * enable(meta)
+            }),
+            configure_u32_range_check(
+                meta,
+                rtable,
+                cols,
+                "rel diff hi range check",
+                diff_hi,
+                |meta| curr!(meta, opcode_bit) * enable(meta),
+            ),
+            configure_u32_range_check(
+                meta,
+                rtable,
+                cols,
+                "rel left hi range check",
+                left_hi,
+                |meta| curr!(meta, opcode_bit) * enable(meta),
+            ),
+            configure_u32_range_check(
+                meta,
+                rtable,
+                cols,
+                "rel right hi range check",
+                right_hi,
+                |meta| curr!(meta, opcode_bit) * enable(meta),
+            ),
+            configure_u32_range_check(
+                meta,
+                rtable,
+                cols,
+                "rel cmp left lo range check",
+                cmp_left_lo,
+                |meta| curr!(meta, opcode_bit) * enable(meta),
+            ),
+            configure_u32_range_check(
+                meta,
+                rtable,
+                cols,
+                "rel cmp right lo range check",
+                cmp_right_lo,
+                |meta| curr!(meta, opcode_bit) * enable(meta),
+            ),
+            configure_u32_range_check(
+                meta,
+                rtable,
+                cols,
+                "rel cmp left hi range check",
+                cmp_left_hi,
+                |meta| curr!(meta, opcode_bit) * enable(meta),
+            ),
+            configure_u32_range_check(
+                meta,
+                rtable,
+                cols,
+                "rel cmp right hi range check",
+                cmp_right_hi,
+                |meta| curr!(meta, opcode_bit) * enable(meta),
+            ),
+        ];
+
+        let config = RelOpConfig {
+            res,
+            left,
+            right,
+            left_hi,
+            right_hi,
+            enable: opcode_bit,
+            rel_op,
+            diff,
+            borrow,
+            diff_hi,
+            borrow_hi,
+            hi_eq,
+            hi_diff_inv,
+            lo_eq,
+            lo_diff_inv,
+            cmp_left_lo,
+            cmp_right_lo,
+            carry_left_lo,
+            carry_right_lo,
+            cmp_left_hi,
+            cmp_right_hi,
+            carry_left_hi,
+            carry_right_hi,
+            range_check_limbs,
+        };
+
+        meta.create_gate("rel comparison gadget", |meta| {
+            // Swap operands for `gt`/`le`, which are defined via the swapped `lt`.
+            let swapped = config.is_any(
+                &[RelOp::SignedGt, RelOp::UnsignedGt, RelOp::SignedLe, RelOp::UnsignedLe],
+                meta,
+            );
+            // Bias the operand's sign-bit limb by 2^(LIMB_WIDTH-1) so the
+            // signed order maps onto the unsigned order: the lo limb for
+            // `I32` (where the hi limb is always zero), the hi limb for `I64`.
+            let signed = config.is_any(
+                &[RelOp::SignedLt, RelOp::SignedGt, RelOp::SignedLe, RelOp::SignedGe],
+                meta,
+            );
+            let is_i64 = config.is_i64(meta);
+            let bias = constant!(F::from(1u64 << (LIMB_WIDTH - 1)));
+            let bias_lo = signed.clone() * (constant_from!(1) - is_i64.clone()) * bias.clone();
+            let bias_hi = signed * is_i64 * bias.clone();
+
+            let two_pow_limb = constant!(F::from(1u64 << LIMB_WIDTH));
+
+            // Bias-then-mod-reduce `left_limb`/`right_limb` (after the
+            // `gt`/`le` swap) by `bias`, witnessing the result in `cmp_left`/
+            // `cmp_right` and the dropped carry in `carry_left`/`carry_right`.
+            // `carry == 1` iff the limb's sign bit was set, which is exactly
+            // what flipping that bit via XOR would also produce — so this
+            // matches the witness below without XOR's non-linearity. For
+            // `Eq`/`Ne` rows `bias == 0`, which forces `carry == 0` and
+            // `cmp_*` to equal the raw (possibly swapped) limb, since both
+            // sides of the reduction are already < 2^LIMB_WIDTH.
+            let mk_limb_gate = |meta: &mut VirtualCells<'_, F>,
+                                left_limb: Expression<F>,
+                                right_limb: Expression<F>,
+                                bias: Expression<F>,
+                                diff: Column<Advice>,
+                                borrow: Column<Advice>,
+                                cmp_left: Column<Advice>,
+                                cmp_right: Column<Advice>,
+                                carry_left: Column<Advice>,
+                                carry_right: Column<Advice>| {
+                let raw_left = left_limb.clone() * (constant_from!(1) - swapped.clone())
+                    + right_limb.clone() * swapped.clone();
+                let raw_right = right_limb * (constant_from!(1) - swapped.clone())
+                    + left_limb * swapped.clone();
+
+                vec![
+                    curr!(meta, carry_left) * (curr!(meta, carry_left) - constant_from!(1))
+                        * enable(meta),
+                    curr!(meta, carry_right) * (curr!(meta, carry_right) - constant_from!(1))
+                        * enable(meta),
+                    (raw_left + bias.clone()
+                        - curr!(meta, carry_left) * two_pow_limb.clone()
+                        - curr!(meta, cmp_left))
+                        * enable(meta),
+                    (raw_right + bias
+                        - curr!(meta, carry_right) * two_pow_limb.clone()
+                        - curr!(meta, cmp_right))
+                        * enable(meta),
+                    curr!(meta, borrow) * (curr!(meta, borrow) - constant_from!(1)) * enable(meta),
+                    (curr!(meta, cmp_left) + curr!(meta, borrow) * two_pow_limb.clone()
+                        - curr!(meta, cmp_right)
+                        - curr!(meta, diff))
+                        * enable(meta),
+                ]
+            };
+
+            // `swapped`/`bias_lo`/`bias_hi` are all zero for `Eq`/`Ne`, so
+            // this gadget also holds (and is enforced) on those rows: `diff`
+            // and `borrow` always witness `lhs - rhs`, which the result gate
+            // below uses to constrain equality.
+            let mut constraints = mk_limb_gate(
+                meta,
+                curr!(meta, left.value.value),
+                curr!(meta, right.value.value),
+                bias_lo,
+                diff,
+                borrow,
+                cmp_left_lo,
+                cmp_right_lo,
+                carry_left_lo,
+                carry_right_lo,
+            );
+            constraints.extend(mk_limb_gate(
+                meta,
+                curr!(meta, left_hi),
+                curr!(meta, right_hi),
+                bias_hi,
+                diff_hi,
+                borrow_hi,
+                cmp_left_hi,
+                cmp_right_hi,
+                carry_left_hi,
+                carry_right_hi,
+            ));
+
+            // `hi_eq`/`lo_eq` are the standard is-zero gadget over
+            // `diff_hi`/`diff`: 1 iff the respective limbs are equal.
+            constraints.push(
+                (curr!(meta, diff_hi) * curr!(meta, hi_diff_inv) - constant_from!(1)
+                    + curr!(meta, hi_eq))
+                    * enable(meta),
+            );
+            constraints.push(curr!(meta, hi_eq) * curr!(meta, diff_hi) * enable(meta));
+            constraints.push(
+                (curr!(meta, diff) * curr!(meta, lo_diff_inv) - constant_from!(1)
+                    + curr!(meta, lo_eq))
+                    * enable(meta),
+            );
+            constraints.push(curr!(meta, lo_eq) * curr!(meta, diff) * enable(meta));
+
+            constraints
+        });
+
+        meta.create_gate("rel eq/ne result", |meta| {
+            let is_eq = config.is_variant(RelOp::Eq, meta);
+            let is_ne = config.is_variant(RelOp::Ne, meta);
+            let eq_result = curr!(meta, hi_eq) * curr!(meta, lo_eq);
+
             vec![
-                curr!(meta, is_eq) * (curr!(meta, is_eq) - constant_from!(1)) * enable(meta),
-                curr!(meta, is_ne) * (curr!(meta, is_ne) - constant_from!(1)) * enable(meta),
-                (curr!(meta, is_eq) + curr!(meta, is_ne) - constant_from!(1)) * enable(meta),
+                (curr!(meta, res) - eq_result.clone()) * is_eq * enable(meta),
+                (curr!(meta, res) - (constant_from!(1) - eq_result)) * is_ne * enable(meta),
             ]
         });
 
-        meta.create_gate("res is bool", |meta| {
-            vec![curr!(meta, res) * (curr!(meta, res) - constant_from!(1)) * enable(meta)]
+        meta.create_gate("rel res selection", |meta| {
+            // `le`/`ge` are the boolean negation of the (possibly swapped) `lt`
+            // computed by the shared gadget above.
+            let negate = config.is_any(
+                &[RelOp::SignedLe, RelOp::UnsignedLe, RelOp::SignedGe, RelOp::UnsignedGe],
+                meta,
+            );
+            // High limbs decide ordering first; fall back to the low limbs'
+            // borrow when the high limbs are equal.
+            let lt_res = curr!(meta, borrow_hi)
+                + curr!(meta, hi_eq) * curr!(meta, borrow);
+            let cmp_res = lt_res.clone() * (constant_from!(1) - negate.clone())
+                + (constant_from!(1) - lt_res) * negate;
+
+            let cmp_active = config.is_any(
+                &[
+                    RelOp::SignedLt,
+                    RelOp::UnsignedLt,
+                    RelOp::SignedGt,
+                    RelOp::UnsignedGt,
+                    RelOp::SignedLe,
+                    RelOp::UnsignedLe,
+                    RelOp::SignedGe,
+                    RelOp::UnsignedGe,
+                ],
+                meta,
+            );
+
+            vec![(curr!(meta, res) - cmp_res) * cmp_active * enable(meta)]
         });
 
         mtable.configure_stack_read_in_table(
@@ -74,7 +444,10 @@ impl<F: FieldExt> EventTableOpcodeConfigBuilder<F> for RelOpConfigBuilder {
             |_meta| constant_from!(1),
             |meta| curr!(meta, common.sp) - constant_from!(1),
             |meta| curr!(meta, right.vtype),
-            |meta| curr!(meta, right.value.value),
+            |meta| {
+                curr!(meta, right.value.value)
+                    + curr!(meta, right_hi) * constant!(F::from(1u64 << LIMB_WIDTH))
+            },
         );
 
         mtable.configure_stack_read_in_table(
@@ -85,7 +458,10 @@ impl<F: FieldExt> EventTableOpcodeConfigBuilder<F> for RelOpConfigBuilder {
             |_meta| constant_from!(2),
             |meta| curr!(meta, common.sp) - constant_from!(2),
             |meta| curr!(meta, left.vtype),
-            |meta| curr!(meta, left.value.value),
+            |meta| {
+                curr!(meta, left.value.value)
+                    + curr!(meta, left_hi) * constant!(F::from(1u64 << LIMB_WIDTH))
+            },
         );
 
         mtable.configure_stack_write_in_table(
@@ -100,32 +476,28 @@ impl<F: FieldExt> EventTableOpcodeConfigBuilder<F> for RelOpConfigBuilder {
         );
 
         meta.create_gate("op bin vtype constrains", |meta| {
-            vec![(curr!(meta, left.vtype) - curr!(meta, right.vtype)) * enable(meta)]
+            let is_i64 = config.is_i64(meta);
+            vec![
+                (curr!(meta, left.vtype) - curr!(meta, right.vtype)) * enable(meta),
+                // Only I32/I32 and I64/I64 operand pairs are supported.
+                is_i64.clone() * (is_i64 - constant_from!(1)) * enable(meta),
+            ]
         });
 
-        Box::new(RelOpConfig {
-            res,
-            left,
-            right,
-            enable: opcode_bit,
-            is_eq,
-            is_ne,
-        })
+        Box::new(config)
     }
 }
 
 impl<F: FieldExt> EventTableOpcodeConfig<F> for RelOpConfig<F> {
     fn opcode(&self, meta: &mut VirtualCells<'_, F>) -> Expression<F> {
+        let rel_op_term = REL_OPS.iter().fold(constant_from!(0), |acc, op| {
+            acc + self.rel_op.value_equals(op, meta)
+                * constant!(bn_to_field(&(BigUint::from(*op as u64) << OPCODE_ARG0_SHIFT)))
+        });
+
         (constant!(bn_to_field(
             &(BigUint::from(OpcodeClass::Rel as u64) << OPCODE_CLASS_SHIFT)
-        )) + curr!(meta, self.is_eq)
-            * constant!(bn_to_field(
-                &(BigUint::from(RelOp::Eq as u64) << OPCODE_ARG0_SHIFT)
-            ))
-            + curr!(meta, self.is_ne)
-                * constant!(bn_to_field(
-                    &(BigUint::from(RelOp::Ne as u64) << OPCODE_ARG0_SHIFT)
-                ))
+        )) + rel_op_term
             + curr!(meta, self.left.vtype)
                 * constant!(bn_to_field(&(BigUint::from(1u64) << OPCODE_ARG1_SHIFT))))
             * curr!(meta, self.enable)
@@ -140,14 +512,131 @@ impl<F: FieldExt> EventTableOpcodeConfig<F> for RelOpConfig<F> {
     }
 
     fn assign(&self, ctx: &mut Context<'_, F>, entry: &EventTableEntry) -> Result<(), Error> {
-        match entry.step_info {
-            specs::step::StepInfo::I32Comp { left, right, value } => {
-                todo!();
-                //self.left.assign(ctx, VarType::I32, left as u32 as u64)?;
-                //self.right.assign(ctx, VarType::I32, right as u32 as u64)?;
+        let (vtype, class, left, right) = match entry.step_info {
+            specs::step::StepInfo::I32Comp { class, left, right, .. } => {
+                (VarType::I32, class, left as u32 as u64, right as u32 as u64)
+            }
+            specs::step::StepInfo::I64Comp { class, left, right, .. } => {
+                (VarType::I64, class, left as u64, right as u64)
             }
             _ => unreachable!(),
+        };
+
+        let is_i64 = vtype == VarType::I64;
+        let (left_lo, left_hi) = (left as u32 as u64, (left >> LIMB_WIDTH) as u32 as u64);
+        let (right_lo, right_hi) = (right as u32 as u64, (right >> LIMB_WIDTH) as u32 as u64);
+
+        // `left`/`right` hold the low limb; the high limb goes to `left_hi`/
+        // `right_hi` below, matching the stack lookups' `lo + hi * 2^32`.
+        self.left.assign(ctx, vtype, left_lo)?;
+        self.right.assign(ctx, vtype, right_lo)?;
+        self.rel_op.assign(ctx, &class)?;
+
+        let swapped = matches!(
+            class,
+            RelOp::SignedGt | RelOp::UnsignedGt | RelOp::SignedLe | RelOp::UnsignedLe
+        );
+        let signed = matches!(
+            class,
+            RelOp::SignedLt | RelOp::SignedGt | RelOp::SignedLe | RelOp::SignedGe
+        );
+        let negate = matches!(
+            class,
+            RelOp::SignedLe | RelOp::UnsignedLe | RelOp::SignedGe | RelOp::UnsignedGe
+        );
+
+        let bias = 1u64 << (LIMB_WIDTH - 1);
+        let bias_lo = if signed && !is_i64 { bias } else { 0 };
+        let bias_hi = if signed && is_i64 { bias } else { 0 };
+
+        let (a_lo, b_lo) = if swapped { (right_lo, left_lo) } else { (left_lo, right_lo) };
+        let (a_hi, b_hi) = if swapped { (right_hi, left_hi) } else { (left_hi, right_hi) };
+
+        // Matches the gate's `raw + bias = carry * 2^n + cmp`: add the bias
+        // and drop the carry out of the top, rather than XOR, so the
+        // witness is the mod-2^n reduction the gate actually enforces.
+        let limb_mod = 1u64 << LIMB_WIDTH;
+        let carry_left_lo = (a_lo + bias_lo) >> LIMB_WIDTH;
+        let cmp_left_lo = (a_lo + bias_lo) & (limb_mod - 1);
+        let carry_right_lo = (b_lo + bias_lo) >> LIMB_WIDTH;
+        let cmp_right_lo = (b_lo + bias_lo) & (limb_mod - 1);
+        let carry_left_hi = (a_hi + bias_hi) >> LIMB_WIDTH;
+        let cmp_left_hi = (a_hi + bias_hi) & (limb_mod - 1);
+        let carry_right_hi = (b_hi + bias_hi) >> LIMB_WIDTH;
+        let cmp_right_hi = (b_hi + bias_hi) & (limb_mod - 1);
+
+        // Matches the gate's `diff = cmp_left - cmp_right + borrow * 2^n`.
+        let borrow_lo = cmp_left_lo < cmp_right_lo;
+        let diff_lo = if borrow_lo {
+            cmp_left_lo + limb_mod - cmp_right_lo
+        } else {
+            cmp_left_lo - cmp_right_lo
+        };
+        let borrow_hi = cmp_left_hi < cmp_right_hi;
+        let diff_hi = if borrow_hi {
+            cmp_left_hi + limb_mod - cmp_right_hi
+        } else {
+            cmp_left_hi - cmp_right_hi
+        };
+
+        let hi_eq = diff_hi == 0;
+        let hi_diff_inv = if hi_eq {
+            F::zero()
+        } else {
+            F::from(diff_hi).invert().unwrap()
+        };
+        let lo_eq = diff_lo == 0;
+        let lo_diff_inv = if lo_eq {
+            F::zero()
+        } else {
+            F::from(diff_lo).invert().unwrap()
+        };
+
+        ctx.assign_advice(self.diff, F::from(diff_lo))?;
+        ctx.assign_advice(self.borrow, F::from(borrow_lo as u64))?;
+        ctx.assign_advice(self.diff_hi, F::from(diff_hi))?;
+        ctx.assign_advice(self.borrow_hi, F::from(borrow_hi as u64))?;
+        ctx.assign_advice(self.hi_eq, F::from(hi_eq as u64))?;
+        ctx.assign_advice(self.hi_diff_inv, hi_diff_inv)?;
+        ctx.assign_advice(self.lo_eq, F::from(lo_eq as u64))?;
+        ctx.assign_advice(self.lo_diff_inv, lo_diff_inv)?;
+        ctx.assign_advice(self.left_hi, F::from(left_hi))?;
+        ctx.assign_advice(self.right_hi, F::from(right_hi))?;
+        ctx.assign_advice(self.cmp_left_lo, F::from(cmp_left_lo))?;
+        ctx.assign_advice(self.cmp_right_lo, F::from(cmp_right_lo))?;
+        ctx.assign_advice(self.carry_left_lo, F::from(carry_left_lo))?;
+        ctx.assign_advice(self.carry_right_lo, F::from(carry_right_lo))?;
+        ctx.assign_advice(self.cmp_left_hi, F::from(cmp_left_hi))?;
+        ctx.assign_advice(self.cmp_right_hi, F::from(cmp_right_hi))?;
+        ctx.assign_advice(self.carry_left_hi, F::from(carry_left_hi))?;
+        ctx.assign_advice(self.carry_right_hi, F::from(carry_right_hi))?;
+
+        for (value, (lo16_col, hi16_col)) in [
+            diff_lo,
+            diff_hi,
+            left_hi,
+            right_hi,
+            cmp_left_lo,
+            cmp_right_lo,
+            cmp_left_hi,
+            cmp_right_hi,
+        ]
+        .into_iter()
+        .zip(self.range_check_limbs)
+        {
+            ctx.assign_advice(lo16_col, F::from(value & 0xffff))?;
+            ctx.assign_advice(hi16_col, F::from(value >> 16))?;
         }
+
+        let lt_res = borrow_hi || (hi_eq && borrow_lo);
+        let res = match class {
+            RelOp::Eq => left == right,
+            RelOp::Ne => left != right,
+            _ if negate => !lt_res,
+            _ => lt_res,
+        };
+        ctx.assign_advice(self.res, F::from(res as u64))?;
+
         Ok(())
     }
 }
@@ -160,22 +649,139 @@ mod tests {
     };
     use halo2_proofs::pairing::bn256::Fr as Fp;
 
-    #[test]
-    fn test_i32_ne() {
-        let textual_repr = r#"
+    fn run_rel_test_with(ty: &str, op: &str, lhs: &str, rhs: &str) {
+        let textual_repr = format!(
+            r#"
                 (module
                     (func (export "test")
-                      (i32.const 1)
-                      (i32.const 2)
-                      (i32.ne)
+                      ({}.const {})
+                      ({}.const {})
+                      ({}.{})
                       (drop)
                     )
                    )
-                "#;
+                "#,
+            ty, lhs, ty, rhs, ty, op
+        );
 
         let compiler = WasmInterpreter::new();
-        let compiled_module = compiler.compile(textual_repr).unwrap();
+        let compiled_module = compiler.compile(&textual_repr).unwrap();
         let execution_log = compiler.run(&compiled_module, "test", vec![]).unwrap();
         run_test_circuit::<Fp>(compiled_module.tables, execution_log.tables).unwrap()
     }
+
+    fn run_rel_test(ty: &str, op: &str) {
+        run_rel_test_with(ty, op, "1", "2");
+    }
+
+    #[test]
+    fn test_i32_ne() {
+        run_rel_test("i32", "ne");
+    }
+
+    #[test]
+    fn test_i32_lt_s() {
+        run_rel_test("i32", "lt_s");
+    }
+
+    #[test]
+    fn test_i32_lt_u() {
+        run_rel_test("i32", "lt_u");
+    }
+
+    #[test]
+    fn test_i32_gt_s() {
+        run_rel_test("i32", "gt_s");
+    }
+
+    #[test]
+    fn test_i32_gt_u() {
+        run_rel_test("i32", "gt_u");
+    }
+
+    #[test]
+    fn test_i32_le_s() {
+        run_rel_test("i32", "le_s");
+    }
+
+    #[test]
+    fn test_i32_le_u() {
+        run_rel_test("i32", "le_u");
+    }
+
+    #[test]
+    fn test_i32_ge_s() {
+        run_rel_test("i32", "ge_s");
+    }
+
+    #[test]
+    fn test_i32_ge_u() {
+        run_rel_test("i32", "ge_u");
+    }
+
+    // `-1` has every bit set, so the sign-bias gadget only gets exercised
+    // (and the XOR/bias-cancellation bug only gets caught) when an operand's
+    // top bit is actually 1.
+    #[test]
+    fn test_i32_lt_s_negative() {
+        run_rel_test_with("i32", "lt_s", "-1", "1");
+    }
+
+    #[test]
+    fn test_i32_gt_s_negative() {
+        run_rel_test_with("i32", "gt_s", "-1", "1");
+    }
+
+    #[test]
+    fn test_i32_le_s_negative() {
+        run_rel_test_with("i32", "le_s", "-1", "1");
+    }
+
+    #[test]
+    fn test_i32_ge_s_negative() {
+        run_rel_test_with("i32", "ge_s", "-1", "1");
+    }
+
+    #[test]
+    fn test_i64_eq() {
+        run_rel_test("i64", "eq");
+    }
+
+    #[test]
+    fn test_i64_lt_s() {
+        run_rel_test("i64", "lt_s");
+    }
+
+    #[test]
+    fn test_i64_lt_u() {
+        run_rel_test("i64", "lt_u");
+    }
+
+    #[test]
+    fn test_i64_ge_u() {
+        run_rel_test("i64", "ge_u");
+    }
+
+    // i64 analogues of the i32 negative-operand cases above: the hi limb
+    // carries the sign bit for i64, so these exercise `bias_hi`/`carry_*_hi`
+    // the same way the i32 cases exercise `bias_lo`/`carry_*_lo`.
+    #[test]
+    fn test_i64_lt_s_negative() {
+        run_rel_test_with("i64", "lt_s", "-1", "1");
+    }
+
+    #[test]
+    fn test_i64_gt_s_negative() {
+        run_rel_test_with("i64", "gt_s", "-1", "1");
+    }
+
+    #[test]
+    fn test_i64_le_s_negative() {
+        run_rel_test_with("i64", "le_s", "-1", "1");
+    }
+
+    #[test]
+    fn test_i64_ge_s_negative() {
+        run_rel_test_with("i64", "ge_s", "-1", "1");
+    }
 }
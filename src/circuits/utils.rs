@@ -0,0 +1,134 @@
+pub mod binary_number;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::Region,
+    plonk::{Advice, Column, Error},
+};
+use std::sync::{Arc, Mutex};
+
+type Cell<F> = (usize, usize, Column<Advice>, F);
+
+/// Per-row witness-assignment context handed to `EventTableOpcodeConfig::assign`.
+///
+/// Opcode `assign` implementations no longer call `region.assign_advice`
+/// directly: that serializes witness generation across the whole event
+/// table. Instead `assign_advice` records `(column, offset, value)` into a
+/// buffer shared with every `RowWriter` split off via [`Context::row_writer`],
+/// so independent rows can compute their witnesses concurrently (e.g. over
+/// `rayon::par_iter`) without ever moving the halo2 `Region` itself across
+/// threads — `Region` is neither `Send` nor constructible outside the single
+/// `assign_region` closure, so only this `Context` may hold one. The buffer
+/// is flushed into the region in one deterministic pass, sorted by `(column
+/// index, offset)`, which reproduces today's sequential layout byte-for-byte
+/// and therefore the same proof. `Context` flushes itself on drop, so a
+/// caller that forgets an explicit `flush()` still gets its witnesses
+/// written rather than silently dropped.
+pub struct Context<'a, F: FieldExt> {
+    pub region: Region<'a, F>,
+    pub offset: usize,
+    buffer: Arc<Mutex<Vec<Cell<F>>>>,
+}
+
+impl<'a, F: FieldExt> Context<'a, F> {
+    pub fn new(region: Region<'a, F>) -> Self {
+        Self {
+            region,
+            offset: 0,
+            buffer: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// A handle to this `Context`'s flush buffer, positioned at `offset`,
+    /// that holds no `Region`. Hand one of these (not the `Context` itself)
+    /// into each parallel task; the owning `Context` stays on the thread
+    /// that has the region and calls `flush` (or just drops) once every task
+    /// has returned. [`Context::assign_rows_parallel`] wires this up over
+    /// `rayon::par_iter` for the common case of assigning many independent
+    /// rows.
+    pub fn row_writer(&self, offset: usize) -> RowWriter<F> {
+        RowWriter {
+            offset,
+            buffer: self.buffer.clone(),
+        }
+    }
+
+    /// Run `assign_row` over `rows` concurrently via `rayon::par_iter` — each
+    /// call gets its own [`RowWriter`] at that row's offset, so no `Region`
+    /// ever crosses a thread boundary — then flush every buffered assignment
+    /// into the region in one deterministic pass. `EventTableOpcodeConfig::assign`
+    /// takes a `Context` rather than a `RowWriter`, so opcode `assign`
+    /// implementations are not run through this path.
+    pub fn assign_rows_parallel<T: Sync>(
+        &mut self,
+        rows: &[(usize, T)],
+        assign_row: impl Fn(&mut RowWriter<F>, &T) -> Result<(), Error> + Sync,
+    ) -> Result<(), Error> {
+        use rayon::prelude::*;
+
+        let buffer = self.buffer.clone();
+        rows.par_iter().try_for_each(|(offset, row)| {
+            let mut writer = RowWriter {
+                offset: *offset,
+                buffer: buffer.clone(),
+            };
+            assign_row(&mut writer, row)
+        })?;
+        self.flush()
+    }
+
+    /// Buffer a cell assignment instead of writing straight into the region.
+    pub fn assign_advice(&mut self, column: Column<Advice>, value: F) -> Result<(), Error> {
+        self.buffer
+            .lock()
+            .unwrap()
+            .push((column.index(), self.offset, column, value));
+        Ok(())
+    }
+
+    /// Flush every buffered assignment into the region in a deterministic
+    /// `(column index, offset)` order, matching the layout a purely
+    /// sequential assignment would have produced. Idempotent: assignments
+    /// already flushed are drained, so calling this more than once (or
+    /// letting `Drop` call it again) is a no-op.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        let mut cells = self.buffer.lock().unwrap();
+        cells.sort_by_key(|(col_idx, offset, _, _)| (*col_idx, *offset));
+        for (_, offset, column, value) in cells.drain(..) {
+            self.region
+                .assign_advice(|| "buffered cell", column, offset, || Ok(value))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, F: FieldExt> Drop for Context<'a, F> {
+    fn drop(&mut self) {
+        // Best-effort only: a caller that forgot an explicit `flush()` still
+        // gets its witnesses written rather than silently dropped, but Drop
+        // can't propagate a `Result` and must not panic (including during
+        // unwinding), so a write failure here is swallowed. Call `flush()`
+        // yourself if you need to observe the error.
+        let _ = self.flush();
+    }
+}
+
+/// A `Context` handle split off for a single row's worth of work, e.g. inside
+/// a `rayon::par_iter` closure. Unlike `Context` it carries no `Region` (see
+/// [`Context::row_writer`]), so it is `Send`/`Sync` whenever `F` is and can
+/// cross thread boundaries freely; its buffered assignments only reach the
+/// region once the owning `Context` flushes.
+pub struct RowWriter<F: FieldExt> {
+    pub offset: usize,
+    buffer: Arc<Mutex<Vec<Cell<F>>>>,
+}
+
+impl<F: FieldExt> RowWriter<F> {
+    pub fn assign_advice(&mut self, column: Column<Advice>, value: F) -> Result<(), Error> {
+        self.buffer
+            .lock()
+            .unwrap()
+            .push((column.index(), self.offset, column, value));
+        Ok(())
+    }
+}
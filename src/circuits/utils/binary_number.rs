@@ -0,0 +1,68 @@
+use crate::{circuits::utils::Context, constant_from, curr};
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, VirtualCells},
+};
+use std::marker::PhantomData;
+
+/// Types that can be encoded as an `N`-bit discriminant, least-significant
+/// bit first. Implemented for the enum selectors that [`BinaryNumberBits`]
+/// is configured over (e.g. `RelOp`).
+pub trait AsBits<const N: usize> {
+    fn as_bits(&self) -> [bool; N];
+}
+
+/// Stores an enum discriminant as `N` binary advice columns, auto-constrains
+/// each column to be boolean, and exposes `value_equals` to build selector
+/// expressions without hand-written "is one of" gates.
+pub struct BinaryNumberBits<F, const N: usize> {
+    pub bits: [Column<Advice>; N],
+    _marker: PhantomData<F>,
+}
+
+impl<F: FieldExt, const N: usize> BinaryNumberBits<F, N> {
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        cols: &mut impl Iterator<Item = Column<Advice>>,
+        enable: impl Fn(&mut VirtualCells<'_, F>) -> Expression<F>,
+    ) -> Self {
+        let bits = [(); N].map(|_| cols.next().unwrap());
+
+        meta.create_gate("binary number bits are boolean", |meta| {
+            bits.iter()
+                .map(|&bit| curr!(meta, bit) * (curr!(meta, bit) - constant_from!(1)) * enable(meta))
+                .collect::<Vec<_>>()
+        });
+
+        Self {
+            bits,
+            _marker: PhantomData,
+        }
+    }
+
+    /// An `Expression` that evaluates to 1 iff the bit columns encode `value`,
+    /// and 0 otherwise.
+    pub fn value_equals<T: AsBits<N>>(
+        &self,
+        value: &T,
+        meta: &mut VirtualCells<'_, F>,
+    ) -> Expression<F> {
+        self.bits
+            .iter()
+            .zip(value.as_bits())
+            .fold(constant_from!(1), |acc, (&bit, set)| {
+                if set {
+                    acc * curr!(meta, bit)
+                } else {
+                    acc * (constant_from!(1) - curr!(meta, bit))
+                }
+            })
+    }
+
+    pub fn assign<T: AsBits<N>>(&self, ctx: &mut Context<'_, F>, value: &T) -> Result<(), Error> {
+        for (&col, set) in self.bits.iter().zip(value.as_bits()) {
+            ctx.assign_advice(col, if set { F::one() } else { F::zero() })?;
+        }
+        Ok(())
+    }
+}